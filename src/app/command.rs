@@ -0,0 +1,54 @@
+//! Parsing for the `:`-prefixed command line (`AppMode::Command`).
+//!
+//! Keeping parsing as a pure function returning a typed `Command` (instead
+//! of matching strings straight out of `events::handle_command_mode`) means
+//! the set of commands is exhaustively matched at the dispatch site and the
+//! parser itself has no dependency on `AppState`.
+
+use super::state::SortMode;
+
+/// A parsed `:`-command, ready to be dispatched against `AppState`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `:add` — open the Add Fruit modal.
+    Add,
+    /// `:delete` — delete the marked fruits, or the selected one if none are marked.
+    Delete,
+    /// `:sort name|volume|length|width|height` — set the sort key explicitly.
+    Sort(SortMode),
+    /// `:w` — save the catalogue.
+    Save,
+    /// `:q` / `:q!` — quit; `force` skips the unsaved-changes guard.
+    Quit { force: bool },
+    /// `:filter <text>` — apply a substring filter.
+    Filter(String),
+}
+
+/// Parse a command line's contents (without the leading `:`). An empty,
+/// unknown or malformed command yields an error message as `Err` so the
+/// caller can surface it via `state.set_error`.
+pub fn parse(input: &str) -> Result<Command, String> {
+    let input = input.trim();
+    let mut parts = input.splitn(2, ' ');
+    let name = parts.next().unwrap_or("");
+    let rest = parts.next().unwrap_or("").trim();
+
+    match name {
+        "" => Err("Empty command".to_string()),
+        "add" | "a" => Ok(Command::Add),
+        "delete" | "d" => Ok(Command::Delete),
+        "sort" => match rest {
+            "name" => Ok(Command::Sort(SortMode::ByName)),
+            "volume" => Ok(Command::Sort(SortMode::ByVolume)),
+            "length" => Ok(Command::Sort(SortMode::ByLength)),
+            "width" => Ok(Command::Sort(SortMode::ByWidth)),
+            "height" => Ok(Command::Sort(SortMode::ByHeight)),
+            _ => Err(format!("Unknown sort key: '{rest}' (want name|volume|length|width|height)")),
+        },
+        "w" => Ok(Command::Save),
+        "q" => Ok(Command::Quit { force: false }),
+        "q!" => Ok(Command::Quit { force: true }),
+        "filter" => Ok(Command::Filter(rest.to_string())),
+        other => Err(format!("Unknown command: {other}")),
+    }
+}
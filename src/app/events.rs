@@ -1,5 +1,9 @@
-use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::crossterm::event::{
+    KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use crate::error::Result;
+use crate::ui::{Interaction, InteractiveWidget};
+use super::command::{self, Command};
 use super::state::{AppState, AppMode};
 
 /// Represents an event that can change the application state
@@ -7,49 +11,192 @@ use super::state::{AppState, AppMode};
 pub enum AppEvent {
     /// User pressed a key
     KeyPress(KeyEvent),
+    /// Mouse click, drag, or scroll
+    Mouse(MouseEvent),
+    /// A bracketed paste, delivered as a single chunk of text
+    Paste(String),
     /// Quit the application
     Quit,
 }
 
-/// Handle keyboard input and update state accordingly
+/// Handle keyboard/mouse/paste input and update state accordingly. Returns
+/// `true` when the app should quit.
 pub fn handle_event(state: &mut AppState, event: AppEvent) -> Result<bool> {
     match event {
-        AppEvent::Quit => return Ok(true),
-        AppEvent::KeyPress(key) => handle_key_press(state, key)?,
+        AppEvent::Quit => Ok(true),
+        AppEvent::KeyPress(key) => handle_key_press(state, key),
+        AppEvent::Mouse(mouse) => {
+            handle_mouse(state, mouse)?;
+            Ok(false)
+        }
+        AppEvent::Paste(text) => {
+            handle_paste(state, &text);
+            Ok(false)
+        }
+    }
+}
+
+fn handle_key_press(state: &mut AppState, key: KeyEvent) -> Result<bool> {
+    match state.mode {
+        AppMode::Normal => handle_normal_mode(state, key),
+        AppMode::Filter => handle_filter_mode(state, key),
+        AppMode::ConfirmDelete => handle_delete_confirm(state, key),
+        AppMode::AddFruit | AppMode::EditFruit => handle_fruit_modal_key(state, key),
+        AppMode::Help => handle_help_modal(state, key),
+        AppMode::Command => handle_command_mode(state, key),
     }
-    Ok(false)
 }
 
-fn handle_key_press(state: &mut AppState, key: KeyEvent) -> Result<()> {
+fn handle_mouse(state: &mut AppState, mouse: MouseEvent) -> Result<()> {
     match state.mode {
-        AppMode::Normal => handle_normal_mode(state, key)?,
-        AppMode::Filter => handle_filter_mode(state, key)?,
-        AppMode::ConfirmDelete => handle_delete_confirm(state, key)?,
-        AppMode::AddFruit => handle_add_fruit_modal(state, key)?,
-        AppMode::EditFruit => handle_edit_fruit_modal(state, key)?,
-        AppMode::Help => handle_help_modal(state, key)?,
+        AppMode::Normal => handle_normal_mouse(state, mouse),
+        AppMode::AddFruit | AppMode::EditFruit => handle_fruit_modal_mouse(state, mouse)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// In Normal mode, a click on a visible row selects it, and the wheel moves
+/// the selection up/down exactly like j/k.
+fn handle_normal_mouse(state: &mut AppState, mouse: MouseEvent) {
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(list_area) = state.hit_areas.list {
+                if rect_contains(list_area, mouse.column, mouse.row) {
+                    let row = (mouse.row - list_area.y) as usize;
+                    state.select_at_row(row);
+                }
+            }
+        }
+        MouseEventKind::ScrollUp => state.select_previous(),
+        MouseEventKind::ScrollDown => state.select_next(),
+        _ => {}
+    }
+}
+
+/// In the Add/Edit modals, a click focuses the field under the cursor, or
+/// submits/cancels when the click lands on the OK/Cancel button.
+fn handle_fruit_modal_mouse(state: &mut AppState, mouse: MouseEvent) -> Result<()> {
+    if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+        return Ok(());
+    }
+
+    if let Some(ok_area) = state.hit_areas.modal_ok {
+        if rect_contains(ok_area, mouse.column, mouse.row) {
+            submit_fruit_modal(state)?;
+            return Ok(());
+        }
+    }
+
+    if let Some(cancel_area) = state.hit_areas.modal_cancel {
+        if rect_contains(cancel_area, mouse.column, mouse.row) {
+            state.modal = None;
+            state.mode = AppMode::Normal;
+            return Ok(());
+        }
+    }
+
+    let field = state
+        .hit_areas
+        .modal_fields
+        .iter()
+        .find(|(_, rect)| rect_contains(*rect, mouse.column, mouse.row))
+        .map(|(field, _)| *field);
+
+    if let (Some(field), Some(modal)) = (field, &mut state.modal) {
+        modal.focused_field = field;
+    }
+
+    Ok(())
+}
+
+/// Validate the open modal and either add or update a fruit from it; the
+/// shared `Submitted` path for both the OK button click and Enter.
+fn submit_fruit_modal(state: &mut AppState) -> Result<()> {
+    let Some(modal) = &mut state.modal else {
+        return Ok(());
+    };
+    match modal.validate_and_build() {
+        Ok(fruit) => {
+            if state.mode == AppMode::AddFruit {
+                state.add_fruit(fruit)?;
+            } else if let Some(idx) = state.selected_fruit_index() {
+                state.update_fruit(idx, fruit)?;
+            }
+            state.modal = None;
+            state.mode = AppMode::Normal;
+        }
+        Err(_) => {
+            // Keep modal open with error showing
+        }
     }
     Ok(())
 }
 
-fn handle_normal_mode(state: &mut AppState, key: KeyEvent) -> Result<()> {
+/// Route a bracketed paste to wherever focused text input currently is: a
+/// modal field in Add/Edit mode, or the filter query in Filter mode.
+/// Ignored everywhere else.
+fn handle_paste(state: &mut AppState, text: &str) {
+    match state.mode {
+        AppMode::AddFruit | AppMode::EditFruit => {
+            if let Some(modal) = &mut state.modal {
+                modal.insert_str(text);
+            }
+        }
+        AppMode::Filter => {
+            let filtered: String = text.chars().filter(|c| !c.is_control()).collect();
+            state.filter_query.push_str(&filtered);
+            let query = state.filter_query.clone();
+            state.update_filter(&query);
+        }
+        _ => {}
+    }
+}
+
+fn rect_contains(rect: ratatui::layout::Rect, x: u16, y: u16) -> bool {
+    x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+}
+
+fn handle_normal_mode(state: &mut AppState, key: KeyEvent) -> Result<bool> {
     // Check for Ctrl+S to save
     if key.code == KeyCode::Char('s') && key.modifiers.contains(KeyModifiers::CONTROL) {
         // Save will be handled in main.rs
-        return Ok(());
+        return Ok(false);
     }
 
     match key.code {
         KeyCode::Char('q') | KeyCode::Esc => {
             if state.dirty {
-                state.set_error("Unsaved changes! Press Ctrl+S to save or press 'q' again to discard".to_string());
-            } else {
-                return Ok(()); // Will be caught by main loop
+                if state.quit_armed {
+                    return Ok(true);
+                }
+                state.quit_armed = true;
+                state.set_error(
+                    "Unsaved changes! Press Ctrl+S to save, or 'q'/Esc again to discard and quit"
+                        .to_string(),
+                );
+            } else if state.error_message.is_some() {
+                state.clear_error();
             }
+            // Otherwise: caught directly by main's loop, which checks for
+            // this before `handle_event` is even called.
+        }
+        KeyCode::Enter if state.error_message.is_some() => {
+            state.clear_error();
+        }
+        _ if state.error_message.is_some() => {
+            // An error (e.g. an unknown `:`-command) takes over the screen
+            // the same way the quit-confirmation one above does; swallow
+            // other keys here too instead of letting them silently open a
+            // modal or change mode underneath the popup.
         }
         KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
         KeyCode::Down | KeyCode::Char('j') => state.select_next(),
         KeyCode::Char('/') => state.mode = AppMode::Filter,
+        KeyCode::Char(':') => {
+            state.command_line.clear();
+            state.mode = AppMode::Command;
+        }
         KeyCode::Char('a') => {
             state.modal = Some(crate::ui::modal::ModalState::new());
             state.mode = AppMode::AddFruit;
@@ -61,15 +208,22 @@ fn handle_normal_mode(state: &mut AppState, key: KeyEvent) -> Result<()> {
             }
         }
         KeyCode::Char('d') => state.mode = AppMode::ConfirmDelete,
+        KeyCode::Char('s') => state.cycle_sort(),
+        KeyCode::Char('S') => state.toggle_sort_direction(),
+        KeyCode::Char(' ') => {
+            if let Some(idx) = state.selected_fruit_index() {
+                state.toggle_mark(idx);
+            }
+        }
         KeyCode::Char('?') => {
             state.mode = AppMode::Help;
         }
         _ => {}
     }
-    Ok(())
+    Ok(false)
 }
 
-fn handle_filter_mode(state: &mut AppState, key: KeyEvent) -> Result<()> {
+fn handle_filter_mode(state: &mut AppState, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Esc => {
             state.mode = AppMode::Normal;
@@ -90,16 +244,14 @@ fn handle_filter_mode(state: &mut AppState, key: KeyEvent) -> Result<()> {
         }
         _ => {}
     }
-    Ok(())
+    Ok(false)
 }
 
-fn handle_delete_confirm(state: &mut AppState, key: KeyEvent) -> Result<()> {
+fn handle_delete_confirm(state: &mut AppState, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Char('y') => {
-            if let Some(idx) = state.selected_fruit_index() {
-                state.delete_fruit(idx)?;
-                state.clear_error();
-            }
+            state.delete_marked_or_selected()?;
+            state.clear_error();
             state.mode = AppMode::Normal;
         }
         KeyCode::Char('n') | KeyCode::Esc => {
@@ -108,77 +260,87 @@ fn handle_delete_confirm(state: &mut AppState, key: KeyEvent) -> Result<()> {
         }
         _ => {}
     }
-    Ok(())
+    Ok(false)
 }
 
-fn handle_add_fruit_modal(state: &mut AppState, key: KeyEvent) -> Result<()> {
-    if let Some(modal) = &mut state.modal {
-        match key.code {
-            // Handle close commands first (before character input)
-            KeyCode::Esc | KeyCode::Char('q') => {
-                state.modal = None;
-                state.mode = AppMode::Normal;
-            }
-            KeyCode::Tab => modal.next_field(),
-            KeyCode::BackTab => modal.prev_field(),
-            KeyCode::Backspace => modal.backspace(),
-            KeyCode::Char(c) => modal.insert_char(c),
-            KeyCode::Enter => {
-                match modal.validate_and_build() {
-                    Ok(fruit) => {
-                        state.add_fruit(fruit)?;
-                        state.modal = None;
-                        state.mode = AppMode::Normal;
-                    }
-                    Err(_) => {
-                        // Keep modal open with error showing
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
-    Ok(())
-}
+/// Drive the open Add/Edit modal's `InteractiveWidget::handle_key` and react
+/// to the `Interaction` it reports: `Submitted` validates and adds/updates
+/// the fruit, `Cancelled` closes the modal, everything else is the widget's
+/// own business (field editing, focus movement).
+fn handle_fruit_modal_key(state: &mut AppState, key: KeyEvent) -> Result<bool> {
+    let interaction = match &mut state.modal {
+        Some(modal) => modal.handle_key(key),
+        None => return Ok(false),
+    };
 
-fn handle_edit_fruit_modal(state: &mut AppState, key: KeyEvent) -> Result<()> {
-    if let Some(modal) = &mut state.modal {
-        match key.code {
-            // Handle close commands first (before character input)
-            KeyCode::Esc | KeyCode::Char('q') => {
-                state.modal = None;
-                state.mode = AppMode::Normal;
-            }
-            KeyCode::Tab => modal.next_field(),
-            KeyCode::BackTab => modal.prev_field(),
-            KeyCode::Backspace => modal.backspace(),
-            KeyCode::Char(c) => modal.insert_char(c),
-            KeyCode::Enter => {
-                match modal.validate_and_build() {
-                    Ok(fruit) => {
-                        if let Some(idx) = state.selected_fruit_index() {
-                            state.update_fruit(idx, fruit)?;
-                        }
-                        state.modal = None;
-                        state.mode = AppMode::Normal;
-                    }
-                    Err(_) => {
-                        // Keep modal open with error showing
-                    }
-                }
-            }
-            _ => {}
+    match interaction {
+        Interaction::Cancelled => {
+            state.modal = None;
+            state.mode = AppMode::Normal;
         }
+        Interaction::Submitted => submit_fruit_modal(state)?,
+        Interaction::Consumed | Interaction::Ignored => {}
     }
-    Ok(())
+    Ok(false)
 }
 
-fn handle_help_modal(state: &mut AppState, key: KeyEvent) -> Result<()> {
+fn handle_help_modal(state: &mut AppState, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') | KeyCode::Enter => {
             state.mode = AppMode::Normal;
         }
         _ => {}
     }
-    Ok(())
+    Ok(false)
+}
+
+/// Drive the `:`-command line's `InteractiveWidget::handle_key`: `Submitted`
+/// parses and dispatches the buffered text, `Cancelled` drops back to
+/// Normal without running anything, everything else is just editing.
+fn handle_command_mode(state: &mut AppState, key: KeyEvent) -> Result<bool> {
+    match state.command_line.handle_key(key) {
+        Interaction::Cancelled => {
+            state.command_line.clear();
+            state.mode = AppMode::Normal;
+            Ok(false)
+        }
+        Interaction::Submitted => {
+            let input = state.command_line.value.clone();
+            state.command_line.clear();
+            state.mode = AppMode::Normal;
+            dispatch_command(state, &input)
+        }
+        Interaction::Consumed | Interaction::Ignored => Ok(false),
+    }
+}
+
+/// Parse and run one command-line entry. Returns `true` only for a quit
+/// that isn't held back by the unsaved-changes guard.
+fn dispatch_command(state: &mut AppState, input: &str) -> Result<bool> {
+    match command::parse(input) {
+        Ok(Command::Add) => {
+            state.modal = Some(crate::ui::modal::ModalState::new());
+            state.mode = AppMode::AddFruit;
+        }
+        Ok(Command::Delete) => {
+            state.mode = AppMode::ConfirmDelete;
+        }
+        Ok(Command::Sort(mode)) => {
+            state.set_sort(mode, state.sort_ascending);
+        }
+        Ok(Command::Save) => {
+            state.save_requested = true;
+        }
+        Ok(Command::Quit { force }) => {
+            if force || !state.dirty {
+                return Ok(true);
+            }
+            state.set_error("Unsaved changes! Use :w to save or :q! to discard".to_string());
+        }
+        Ok(Command::Filter(text)) => {
+            state.update_filter(&text);
+        }
+        Err(msg) => state.set_error(msg),
+    }
+    Ok(false)
 }
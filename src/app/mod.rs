@@ -1,5 +1,7 @@
+pub mod command;
 pub mod state;
 pub mod events;
 
-pub use state::AppState;
+pub use command::Command;
+pub use state::{AppState, AppMode, SortMode};
 pub use events::{AppEvent, handle_event};
@@ -1,6 +1,77 @@
+use std::collections::HashSet;
+
 use fruitdata::FruitDimensions;
+use ratatui::layout::Rect;
+use ratatui::widgets::ListState;
+
 use crate::error::Result;
-use crate::ui::modal::ModalState;
+use crate::ui::modal::{InputField, ModalState};
+use crate::ui::widget::TextInputState;
+
+/// Accepts any character unchanged; used by widgets with no input
+/// restrictions, like the `:`-command line.
+fn accept_any(c: char, _existing: &str) -> Option<char> {
+    Some(c)
+}
+
+/// The last-rendered rectangles that mouse handling hit-tests against.
+/// Populated by the `ui::render` functions each frame; empty/`None` until
+/// the first draw, so a click before that is simply a no-op.
+#[derive(Debug, Clone, Default)]
+pub struct HitAreas {
+    /// The bordered area the fruit list was drawn into.
+    pub list: Option<Rect>,
+    /// Rect of each modal input field, keyed by which field it is.
+    pub modal_fields: Vec<(InputField, Rect)>,
+    /// Rect of the modal's OK/save button, if a modal is open.
+    pub modal_ok: Option<Rect>,
+    /// Rect of the modal's Cancel button, if a modal is open.
+    pub modal_cancel: Option<Rect>,
+}
+
+/// A key to sort the fruit catalogue by, cycled with `s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortMode {
+    ByName,
+    ByVolume,
+    ByLength,
+    ByWidth,
+    ByHeight,
+}
+
+impl SortMode {
+    /// Cycle to the next sort mode, wrapping back to `ByName`.
+    pub fn next(self) -> Self {
+        match self {
+            SortMode::ByName => SortMode::ByVolume,
+            SortMode::ByVolume => SortMode::ByLength,
+            SortMode::ByLength => SortMode::ByWidth,
+            SortMode::ByWidth => SortMode::ByHeight,
+            SortMode::ByHeight => SortMode::ByName,
+        }
+    }
+
+    /// Short label shown in the list title.
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::ByName => "name",
+            SortMode::ByVolume => "volume",
+            SortMode::ByLength => "length",
+            SortMode::ByWidth => "width",
+            SortMode::ByHeight => "height",
+        }
+    }
+
+    fn key(self, fruit: &FruitDimensions) -> f32 {
+        match self {
+            SortMode::ByName => 0.0, // names are compared separately, see `recompute_filtered_indices`
+            SortMode::ByVolume => fruit.volume(),
+            SortMode::ByLength => fruit.length,
+            SortMode::ByWidth => fruit.width,
+            SortMode::ByHeight => fruit.height,
+        }
+    }
+}
 
 /// Represents the current application mode
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -17,6 +88,8 @@ pub enum AppMode {
     ConfirmDelete,
     /// Showing help modal
     Help,
+    /// Entering a `:`-command at the bottom command line
+    Command,
 }
 
 /// Main application state
@@ -32,81 +105,116 @@ pub struct AppState {
     pub dirty: bool,
     /// Filter query for search
     pub filter_query: String,
-    /// Filtered fruit indices (when filtering)
+    /// True indices into `fruits`, filtered by `filter_query` and ordered by
+    /// `sort_mode`/`sort_ascending`. This is what gets displayed and
+    /// navigated; `selected_index` is an index into *this* vector.
     pub filtered_indices: Vec<usize>,
+    /// Current sort key for the catalogue
+    pub sort_mode: SortMode,
+    /// Sort direction: true for ascending, false for descending
+    pub sort_ascending: bool,
+    /// True indices of fruits marked for a bulk action (currently bulk delete)
+    pub marked: HashSet<usize>,
     /// Error message to display
     pub error_message: Option<String>,
     /// Modal state for add/edit operations
     pub modal: Option<ModalState>,
+    /// Ratatui's list widget state (selection + scroll offset). Kept across
+    /// frames (rather than rebuilt per-draw) so the viewport's scroll
+    /// position survives redraws instead of snapping back to the top.
+    pub list_state: ListState,
+    /// Last-rendered rectangles, refreshed by `ui::render` every frame, for
+    /// mouse hit-testing.
+    pub hit_areas: HitAreas,
+    /// Buffer for the `:`-command line, active while `mode == AppMode::Command`.
+    pub command_line: TextInputState,
+    /// Set by the `:w` command; `main`'s event loop notices it, performs the
+    /// save, and clears it again (the command-line equivalent of the
+    /// Ctrl+S path, which `main` intercepts directly instead).
+    pub save_requested: bool,
+    /// Set after the first `q`/Esc press with unsaved changes, so a second
+    /// press actually discards and quits instead of re-showing the same
+    /// warning forever.
+    pub quit_armed: bool,
 }
 
 impl AppState {
     /// Create a new app state with the given fruits
     pub fn new(fruits: Vec<FruitDimensions>) -> Self {
-        let filtered_indices = (0..fruits.len()).collect();
-        Self {
+        let mut state = Self {
             fruits,
             selected_index: 0,
             mode: AppMode::Normal,
             dirty: false,
             filter_query: String::new(),
-            filtered_indices,
+            filtered_indices: Vec::new(),
+            sort_mode: SortMode::ByName,
+            sort_ascending: true,
+            marked: HashSet::new(),
             error_message: None,
             modal: None,
+            list_state: ListState::default(),
+            hit_areas: HitAreas::default(),
+            command_line: TextInputState::new(accept_any),
+            save_requested: false,
+            quit_armed: false,
+        };
+        state.recompute_filtered_indices();
+        state.sync_list_state();
+        state
+    }
+
+    /// Keep `list_state`'s selection in sync with `selected_index`. Only
+    /// the selection is touched here; the scroll offset is left for
+    /// ratatui to manage as the list is rendered.
+    fn sync_list_state(&mut self) {
+        if self.filtered_indices.is_empty() {
+            self.list_state.select(None);
+        } else {
+            self.list_state.select(Some(self.selected_index));
         }
     }
 
     /// Get the currently selected fruit
     pub fn selected_fruit(&self) -> Option<&FruitDimensions> {
-        if self.is_filtering() {
-            self.filtered_indices.get(self.selected_index).and_then(|&i| self.fruits.get(i))
-        } else {
-            self.fruits.get(self.selected_index)
-        }
+        self.filtered_indices.get(self.selected_index).and_then(|&i| self.fruits.get(i))
     }
 
     /// Get the actual index of the selected fruit in the main fruits vec
     pub fn selected_fruit_index(&self) -> Option<usize> {
-        if self.is_filtering() {
-            self.filtered_indices.get(self.selected_index).copied()
-        } else {
-            Some(self.selected_index)
-        }
+        self.filtered_indices.get(self.selected_index).copied()
     }
 
-    /// Get the display list (either all fruits or filtered)
+    /// Get the display list (filtered and sorted)
     pub fn display_fruits(&self) -> Vec<&FruitDimensions> {
-        if self.is_filtering() {
-            self.filtered_indices.iter().filter_map(|&i| self.fruits.get(i)).collect()
-        } else {
-            self.fruits.iter().collect()
-        }
+        self.filtered_indices.iter().filter_map(|&i| self.fruits.get(i)).collect()
     }
 
     /// Move selection up
     pub fn select_previous(&mut self) {
-        let display_len = if self.is_filtering() {
-            self.filtered_indices.len()
-        } else {
-            self.fruits.len()
-        };
-
-        if display_len > 0 && self.selected_index > 0 {
+        if !self.filtered_indices.is_empty() && self.selected_index > 0 {
             self.selected_index -= 1;
         }
+        self.sync_list_state();
     }
 
     /// Move selection down
     pub fn select_next(&mut self) {
-        let display_len = if self.is_filtering() {
-            self.filtered_indices.len()
-        } else {
-            self.fruits.len()
-        };
-
-        if display_len > 0 && self.selected_index < display_len - 1 {
+        if self.selected_index + 1 < self.filtered_indices.len() {
             self.selected_index += 1;
         }
+        self.sync_list_state();
+    }
+
+    /// Select the fruit at list-relative row `row` (0-based within the
+    /// visible, scrolled viewport), as produced by a mouse click inside
+    /// `hit_areas.list`. A no-op if `row` falls outside the current list.
+    pub fn select_at_row(&mut self, row: usize) {
+        let index = row + self.list_state.offset();
+        if index < self.filtered_indices.len() {
+            self.selected_index = index;
+            self.sync_list_state();
+        }
     }
 
     /// Check if currently in filter mode
@@ -117,25 +225,79 @@ impl AppState {
     /// Update the filter and rebuild filtered_indices
     pub fn update_filter(&mut self, query: &str) {
         self.filter_query = query.to_lowercase();
-        self.filtered_indices = self.fruits
-            .iter()
-            .enumerate()
-            .filter(|(_, fruit)| fruit.name.to_lowercase().contains(&self.filter_query))
-            .map(|(i, _)| i)
-            .collect();
+        self.recompute_filtered_indices();
         self.selected_index = 0;
+        self.sync_list_state();
     }
 
     /// Clear the filter
     pub fn clear_filter(&mut self) {
         self.filter_query.clear();
-        self.filtered_indices = (0..self.fruits.len()).collect();
+        self.recompute_filtered_indices();
+    }
+
+    /// Set the sort key and direction explicitly
+    pub fn set_sort(&mut self, mode: SortMode, ascending: bool) {
+        self.sort_mode = mode;
+        self.sort_ascending = ascending;
+        self.recompute_filtered_indices();
+    }
+
+    /// Cycle to the next sort key, keeping the current direction
+    pub fn cycle_sort(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+        self.recompute_filtered_indices();
+    }
+
+    /// Toggle ascending/descending for the current sort key
+    pub fn toggle_sort_direction(&mut self) {
+        self.sort_ascending = !self.sort_ascending;
+        self.recompute_filtered_indices();
+    }
+
+    /// Rebuild `filtered_indices` from `fruits`, applying the current
+    /// substring filter and then the current sort key/direction. Called
+    /// after any change to `fruits`, `filter_query`, `sort_mode`, or
+    /// `sort_ascending` so the two stay in sync.
+    fn recompute_filtered_indices(&mut self) {
+        let mut indices: Vec<usize> = self
+            .fruits
+            .iter()
+            .enumerate()
+            .filter(|(_, fruit)| fruit.name.to_lowercase().contains(&self.filter_query))
+            .map(|(i, _)| i)
+            .collect();
+
+        indices.sort_by(|&a, &b| {
+            let fruit_a = &self.fruits[a];
+            let fruit_b = &self.fruits[b];
+            let ordering = if self.sort_mode == SortMode::ByName {
+                fruit_a.name.to_lowercase().cmp(&fruit_b.name.to_lowercase())
+            } else {
+                self.sort_mode
+                    .key(fruit_a)
+                    .partial_cmp(&self.sort_mode.key(fruit_b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            };
+            if self.sort_ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+
+        self.filtered_indices = indices;
+        if self.selected_index >= self.filtered_indices.len() {
+            self.selected_index = self.filtered_indices.len().saturating_sub(1);
+        }
+        self.sync_list_state();
     }
 
     /// Add a new fruit
     pub fn add_fruit(&mut self, fruit: FruitDimensions) -> Result<()> {
         self.fruits.push(fruit);
         self.dirty = true;
+        self.recompute_filtered_indices();
         Ok(())
     }
 
@@ -146,6 +308,7 @@ impl AppState {
         }
         self.fruits[index] = fruit;
         self.dirty = true;
+        self.recompute_filtered_indices();
         Ok(())
     }
 
@@ -156,13 +319,60 @@ impl AppState {
         }
         self.fruits.remove(index);
         self.dirty = true;
+        self.unmark_and_shift(index);
+        self.recompute_filtered_indices();
 
-        // Adjust selected index if needed
-        if self.selected_index >= self.fruits.len() && self.fruits.len() > 0 {
-            self.selected_index = self.fruits.len() - 1;
+        Ok(())
+    }
+
+    /// Toggle whether the true fruit index `index` is marked for bulk action
+    pub fn toggle_mark(&mut self, index: usize) {
+        if !self.marked.remove(&index) {
+            self.marked.insert(index);
         }
+    }
 
-        Ok(())
+    /// Clear all marks
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+
+    /// The true indices a delete action should act on: the marked set if
+    /// non-empty, otherwise just the currently selected fruit.
+    pub fn delete_targets(&self) -> Vec<usize> {
+        if self.marked.is_empty() {
+            self.selected_fruit_index().into_iter().collect()
+        } else {
+            let mut targets: Vec<usize> = self.marked.iter().copied().collect();
+            targets.sort_unstable();
+            targets
+        }
+    }
+
+    /// Delete every fruit in `delete_targets()`, clearing marks afterwards.
+    /// Returns the number of fruits removed.
+    pub fn delete_marked_or_selected(&mut self) -> Result<usize> {
+        let mut targets = self.delete_targets();
+        targets.sort_unstable_by(|a, b| b.cmp(a)); // remove highest index first
+
+        for index in &targets {
+            self.delete_fruit(*index)?;
+        }
+        self.clear_marks();
+
+        Ok(targets.len())
+    }
+
+    /// Remove `removed` from the marked set and shift every mark above it
+    /// down by one, keeping marks pointing at the same logical fruit after
+    /// a deletion.
+    fn unmark_and_shift(&mut self, removed: usize) {
+        self.marked = self
+            .marked
+            .iter()
+            .filter(|&&i| i != removed)
+            .map(|&i| if i > removed { i - 1 } else { i })
+            .collect();
     }
 
     /// Set an error message
@@ -170,8 +380,10 @@ impl AppState {
         self.error_message = Some(msg.into());
     }
 
-    /// Clear the error message
+    /// Clear the error message, and with it any pending discard-and-quit
+    /// confirmation (see `quit_armed`) it may have been warning about.
     pub fn clear_error(&mut self) {
         self.error_message = None;
+        self.quit_armed = false;
     }
 }
@@ -0,0 +1,242 @@
+//! User-configurable UI layout and styling, loaded from a config file.
+//!
+//! The on-disk format mirrors the kind of `PanelUiConfig`/`LayoutOptions`
+//! xplr exposes: panel split constraints, margins, border style, and per
+//! panel colors/titles. A missing config file falls back to the defaults
+//! baked in here so the app always has something to render; a malformed
+//! one also falls back but surfaces `AppError::Config` to the caller.
+
+use std::path::Path;
+
+use ratatui::style::Color;
+use ratatui::widgets::BorderType;
+use serde::Deserialize;
+
+use crate::error::{AppError, Result};
+
+/// Selectable border styles for panels and modals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BorderKind {
+    Plain,
+    Rounded,
+    Double,
+}
+
+impl Default for BorderKind {
+    fn default() -> Self {
+        BorderKind::Plain
+    }
+}
+
+impl From<BorderKind> for BorderType {
+    fn from(kind: BorderKind) -> Self {
+        match kind {
+            BorderKind::Plain => BorderType::Plain,
+            BorderKind::Rounded => BorderType::Rounded,
+            BorderKind::Double => BorderType::Double,
+        }
+    }
+}
+
+/// Styling knobs for a single panel (the fruit list or the details pane).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PanelConfig {
+    pub title: String,
+    pub fg: String,
+    pub bg: String,
+    pub highlight_fg: String,
+    pub highlight_bg: String,
+}
+
+impl PanelConfig {
+    fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            fg: "white".to_string(),
+            bg: "reset".to_string(),
+            highlight_fg: "yellow".to_string(),
+            highlight_bg: "reset".to_string(),
+        }
+    }
+
+    pub fn fg(&self) -> Color {
+        parse_color(&self.fg)
+    }
+
+    pub fn bg(&self) -> Color {
+        parse_color(&self.bg)
+    }
+
+    pub fn highlight_fg(&self) -> Color {
+        parse_color(&self.highlight_fg)
+    }
+
+    pub fn highlight_bg(&self) -> Color {
+        parse_color(&self.highlight_bg)
+    }
+}
+
+impl Default for PanelConfig {
+    fn default() -> Self {
+        PanelConfig::new("Panel")
+    }
+}
+
+/// Mirrors `PanelConfig` with every field optional, so deserializing a
+/// `[list]`/`[details]` table that only sets e.g. `fg` doesn't clobber the
+/// panel's own default title (`PanelConfig`'s container-level
+/// `#[serde(default)]` can't tell "Fruits" from "Details" apart, since both
+/// panels share the same type). `UiConfig::load` merges this over the
+/// matching panel's real default instead.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PanelConfigPartial {
+    title: Option<String>,
+    fg: Option<String>,
+    bg: Option<String>,
+    highlight_fg: Option<String>,
+    highlight_bg: Option<String>,
+}
+
+impl PanelConfig {
+    /// Overlay the fields a user actually set in `partial` onto `self`,
+    /// keeping `self`'s value (the panel's real default) for anything left
+    /// unset.
+    fn merge(mut self, partial: PanelConfigPartial) -> Self {
+        if let Some(title) = partial.title {
+            self.title = title;
+        }
+        if let Some(fg) = partial.fg {
+            self.fg = fg;
+        }
+        if let Some(bg) = partial.bg {
+            self.bg = bg;
+        }
+        if let Some(highlight_fg) = partial.highlight_fg {
+            self.highlight_fg = highlight_fg;
+        }
+        if let Some(highlight_bg) = partial.highlight_bg {
+            self.highlight_bg = highlight_bg;
+        }
+        self
+    }
+}
+
+/// Split and margin of the two main panels.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// Width of the list panel, as a layout percentage.
+    pub list_constraint: u16,
+    /// Width of the details panel, as a layout percentage.
+    pub details_constraint: u16,
+    /// Margin (in cells) applied around the whole layout.
+    pub margin: u16,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            list_constraint: 60,
+            details_constraint: 40,
+            margin: 1,
+        }
+    }
+}
+
+/// Top-level UI configuration, used throughout `ui::render`.
+///
+/// Load with [`UiConfig::load`], which falls back to [`UiConfig::default`]
+/// whenever the file is absent (but surfaces `AppError::Config` if it
+/// exists and fails to parse).
+#[derive(Debug, Clone)]
+pub struct UiConfig {
+    pub layout: LayoutConfig,
+    pub border: BorderKind,
+    pub list: PanelConfig,
+    pub details: PanelConfig,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            layout: LayoutConfig::default(),
+            border: BorderKind::default(),
+            list: PanelConfig::new("Fruits"),
+            details: PanelConfig::new("Details"),
+        }
+    }
+}
+
+/// On-disk shape of `UiConfig`: identical except `list`/`details` are
+/// `PanelConfigPartial`, so a table that only overrides colors doesn't
+/// overwrite the panel's default title. See `UiConfig::load`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct UiConfigRaw {
+    layout: LayoutConfig,
+    border: BorderKind,
+    list: PanelConfigPartial,
+    details: PanelConfigPartial,
+}
+
+impl From<UiConfigRaw> for UiConfig {
+    fn from(raw: UiConfigRaw) -> Self {
+        let defaults = UiConfig::default();
+        Self {
+            layout: raw.layout,
+            border: raw.border,
+            list: defaults.list.merge(raw.list),
+            details: defaults.details.merge(raw.details),
+        }
+    }
+}
+
+impl UiConfig {
+    /// Load configuration from `path`. A missing file yields the defaults;
+    /// an existing-but-unparseable file surfaces as `AppError::Config`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = std::fs::read_to_string(path)?;
+        let is_json = path.extension().and_then(|ext| ext.to_str()) == Some("json");
+
+        let raw: UiConfigRaw = if is_json {
+            serde_json::from_str(&contents)
+                .map_err(|e| AppError::Config(format!("{}: {e}", path.display())))?
+        } else {
+            toml::from_str(&contents)
+                .map_err(|e| AppError::Config(format!("{}: {e}", path.display())))?
+        };
+
+        Ok(raw.into())
+    }
+
+    pub fn border_type(&self) -> BorderType {
+        self.border.into()
+    }
+}
+
+/// Parse a handful of common color names plus `#rrggbb`; unknown names
+/// fall back to `Color::Reset` rather than failing config load entirely.
+fn parse_color(name: &str) -> Color {
+    match name.to_lowercase().as_str() {
+        "reset" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "white" => Color::White,
+        other => other.parse().unwrap_or(Color::Reset),
+    }
+}
@@ -14,7 +14,6 @@ pub enum AppError {
     Validation(String),
 
     #[error("Configuration error: {0}")]
-    #[allow(dead_code)]
     Config(String),
 
     #[error("An unexpected error occurred: {0}")]
@@ -3,32 +3,28 @@
 use color_eyre::eyre::Result;
 
 // Import types and functions from the local `fruitdata` crate:
-// - FruitDimensions: represents a single fruit's size information (length, width, height)
 // - initialise_fruit_catalogue: creates a default list of fruits
 // - load_catalogue: reads a list of fruits from a JSON file
-use fruitdata::{FruitDimensions, initialise_fruit_catalogue, load_catalogue};
-
-// Import UI components from `ratatui`, a library for building terminal user interfaces (TUIs).
-// Think of these like building blocks for creating a fancy text-based interface.
-use ratatui::{
-    // DefaultTerminal: the main object that controls drawing on the terminal screen
-    DefaultTerminal,
-    // crossterm::event: handles keyboard input (when the user presses keys)
-    // Event: represents something that happened (like a keypress)
-    // KeyCode: identifies which key was pressed (Up, Down, 'q', etc.)
-    crossterm::event::{self, Event, KeyCode},
-    // Layout: helps divide the terminal screen into sections (left panel, right panel, etc.)
-    // Constraint: specifies how large each section should be (e.g., 60% width)
-    // Direction: determines if sections are arranged horizontally or vertically
-    layout::{Constraint, Direction, Layout},
-    // Widgets: the visual components we can draw on screen
-    // Block: a box with a border and title
-    // Borders: creates visual lines around a widget
-    // List: displays a scrollable list of items (like the fruit names)
-    // ListItem: a single item in a list
-    // Paragraph: displays text content
-    widgets::{Block, Borders, List, ListItem, Paragraph},
-};
+// - save_catalogue: writes the current list of fruits back to a JSON file
+use fruitdata::{initialise_fruit_catalogue, load_catalogue, save_catalogue};
+
+// Handles for keyboard input from crossterm (re-exported through ratatui).
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use ratatui::DefaultTerminal;
+
+mod app;
+mod config;
+mod error;
+mod tui;
+mod ui;
+
+use app::{handle_event, AppEvent, AppMode, AppState};
+use config::UiConfig;
+
+/// Where the fruit catalogue is persisted between runs.
+const CATALOGUE_PATH: &str = "fruits.json";
+/// Where the optional UI layout/styling config is read from.
+const CONFIG_PATH: &str = "ui.toml";
 
 // The main() function is where every Rust program starts executing.
 // It returns Result<()>, meaning it can either succeed (Ok) or fail (Err) with an error message.
@@ -37,186 +33,112 @@ fn main() -> Result<()> {
     // and includes a backtrace if something goes wrong. The '?' operator will use this.
     color_eyre::install()?;
 
+    // Make sure a panic while in raw mode / the alternate screen doesn't
+    // leave the user's terminal corrupted and the backtrace unreadable.
+    tui::init_panic_hook();
+
     // Initialize the terminal for drawing. This:
     // - Switches to "raw mode" (reads keypresses directly)
     // - Switches to an alternate screen (so we don't overwrite your terminal history)
     // - Returns a mutable terminal object we can draw on
     let mut terminal = ratatui::init();
+    tui::enable_mouse_capture()?;
+    tui::enable_bracketed_paste()?;
 
     // Call the run() function to start the main app loop.
-    // If run() returns an error, we catch it with 'if let Err(e)'.
-    if let Err(e) = run(&mut terminal) {
-        // If an error occurred, restore the terminal to normal mode before showing the error.
-        // This prevents leaving the user's terminal in a broken state.
-        ratatui::restore();
-        // Return the error so it gets printed and the program exits
-        return Err(e);
-    }
+    let result = run(&mut terminal);
 
-    // If the app exited normally (user pressed q/Esc), restore the terminal
-    // so the user can see their normal terminal prompt again.
+    // Always restore the terminal to normal mode, whether run() succeeded or not,
+    // so the user's terminal is never left in a broken state.
+    let paste_result = tui::disable_bracketed_paste();
+    let mouse_result = tui::disable_mouse_capture();
     ratatui::restore();
+    paste_result?;
+    mouse_result?;
 
-    // Return Ok(()) to indicate the program succeeded
-    Ok(())
+    result
 }
 
 // The run() function contains the main application logic and event loop.
 // It takes a mutable reference to the terminal so it can draw on it.
 fn run(terminal: &mut DefaultTerminal) -> Result<()> {
     // Load the list of fruits from "fruits.json". If the file doesn't exist or fails to load,
-    // unwrap_or_else() runs the closure (the |_| { } block) which creates a default list.
-    let fruits: Vec<FruitDimensions> =
-        load_catalogue("fruits.json").unwrap_or_else(|_| initialise_fruit_catalogue());
-
-    // Initialize variables that track the state of the UI:
-
-    // selected: which fruit in the list is currently highlighted (starts at 0, the first fruit)
-    let mut selected: usize = 0;
-
-    // list_state: ratatui's internal state for tracking which list item is selected
-    // This is separate from 'selected' because ratatui needs to manage its own state
-    let mut list_state = ratatui::widgets::ListState::default();
-
-    // command_buffer: stores characters the user types (not actively used here, but available)
-    let mut command_buffer = String::new();
-
-    // If there are fruits in the list, mark the first one (index 0) as selected.
-    // Some() wraps the index because ListState::select() expects Option<usize>
-    // (it could be None if we want nothing selected)
-    if !fruits.is_empty() {
-        list_state.select(Some(selected));
-    }
+    // unwrap_or_else() runs the closure which creates a default list.
+    let fruits = load_catalogue(CATALOGUE_PATH).unwrap_or_else(|_| initialise_fruit_catalogue());
+
+    // Load UI layout/styling config, falling back to the built-in defaults
+    // when no config file is present (or it fails to parse). A parse error
+    // is still surfaced to the user instead of silently vanishing.
+    let mut state = AppState::new(fruits);
+    let config = UiConfig::load(CONFIG_PATH).unwrap_or_else(|e| {
+        state.set_error(format!("Failed to load {CONFIG_PATH}: {e}"));
+        UiConfig::default()
+    });
 
     // Main event loop: this loop runs repeatedly, drawing the UI and handling user input.
     loop {
-        // terminal.draw() takes a closure (a block of code) that describes what to draw.
-        // The 'frame' object is our canvas for drawing on the terminal.
-        terminal.draw(|frame| {
-            // Split the terminal screen into two sections (left and right):
-            // - Left side: 60% of the width (for the fruit list)
-            // - Right side: 40% of the width (for detailed info about the selected fruit)
-            // margin(1) adds 1 space of padding around all edges
-            let chunks = Layout::default()
-                .direction(Direction::Horizontal)
-                .margin(1)
-                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-                .split(frame.area());
-
-            // Convert the fruits vector into a list of ListItem widgets.
-            // .iter() loops through each fruit, .map() transforms each one,
-            // and .collect() gathers them into a Vec<ListItem>
-            let items: Vec<ListItem> = fruits
-                .iter()
-                .map(|f| ListItem::new(f.name.clone()))
-                .collect();
-
-            // Create the left-side list widget with:
-            // - The items (fruit names)
-            // - A title and border around the list
-            // - A ">> " symbol to highlight the selected item
-            let list = List::new(items)
-                .block(Block::default().title("Fruits — (Up/Down/j/k: navigate, Enter: none, Esc/q: quit)").borders(Borders::ALL))
-                .highlight_symbol(">> ");
-
-            // Render the list widget in the left section (chunks[0]) and update list_state
-            // to show which item is currently selected
-            frame.render_stateful_widget(list, chunks[0], &mut list_state);
-
-            // Create the right-side details pane.
-            // Use an if-else expression to show either:
-            // - "No fruits available" if the list is empty
-            // - Detailed information about the selected fruit otherwise
-            let details = if fruits.is_empty() {
-                // Empty case: show a message
-                Paragraph::new("No fruits available").block(Block::default().title("Details").borders(Borders::ALL))
-            } else {
-                // Non-empty case: display info about the currently selected fruit
-                let f = &fruits[selected];
-
-                // Format a string with the fruit's information.
-                // {:.2} means "print this number with 2 decimal places"
-                let txt = format!(
-                    "Name: {}\n\nDimensions:\n  Length: {}\n  Width : {}\n  Height: {}\n\nVolume: {:.2}",
-                    f.name,
-                    f.length,
-                    f.width,
-                    f.height,
-                    f.volume()
-                );
-                Paragraph::new(txt).block(Block::default().title("Details").borders(Borders::ALL))
-            };
-
-            // Render the details widget in the right section (chunks[1])
-            frame.render_widget(details, chunks[1]);
-        })?;
-
-        // Handle keyboard input.
-        // event::read()? blocks and waits for the next keyboard input from the user.
-        if let Event::Key(key) = event::read()? {
-            // Match checks which key was pressed and reacts accordingly.
-            match key.code {
-                // Quit the app if user presses Escape or the 'q' key
-                // 'break' exits the loop, ending the run() function
-                KeyCode::Esc | KeyCode::Char('q') => break,
-
-                // Move selection up if user presses Up arrow or 'k' (vim-style)
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if selected > 0 {
-                        // If we're not already at the top, move up (decrease the index)
-                        selected -= 1;
-                    } else {
-                        // If we're at the top, stay at the top (don't go negative)
-                        selected = 0;
-                    }
-                    // Update the list state so ratatui redraws with the new selection
-                    list_state.select(Some(selected));
-                }
+        terminal.draw(|frame| ui::render(frame, &mut state, &config))?;
 
-                // Move selection down if user presses Down arrow or 'j' (vim-style)
-                KeyCode::Down | KeyCode::Char('j') => {
-                    // Only move down if there are fruits and we're not already at the bottom
-                    if !fruits.is_empty() && selected + 1 < fruits.len() {
-                        selected += 1;
-                        list_state.select(Some(selected));
-                    }
+        let key = match event::read()? {
+            Event::Key(key) => key,
+            Event::Mouse(mouse) => {
+                if handle_event(&mut state, AppEvent::Mouse(mouse))? {
+                    break;
                 }
-
-                // The ':' key initiates a command (vim-style), so clear any previous input
-                KeyCode::Char(':') => {
-                    command_buffer.clear();
+                continue;
+            }
+            Event::Paste(text) => {
+                if handle_event(&mut state, AppEvent::Paste(text))? {
+                    break;
                 }
+                continue;
+            }
+            _ => continue,
+        };
 
-                // Handle other regular character input
-                KeyCode::Char(c) => {
-                    // Only add to the buffer if the buffer is already being used (e.g., after ':')
-                    if !command_buffer.is_empty() {
-                        command_buffer.push(c);
-                        // Check if the user typed 'q' to quit
-                        if command_buffer == "q" {
-                            break;
-                        }
-                    }
-                }
+        // Ctrl+S saves the catalogue regardless of mode.
+        if key.code == KeyCode::Char('s') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            save(&mut state);
+            continue;
+        }
 
-                // Handle backspace: delete the last character from the command buffer
-                KeyCode::Backspace => {
-                    if !command_buffer.is_empty() {
-                        command_buffer.pop();
-                    }
-                }
+        // In Normal mode, quitting with unsaved changes (or dismissing a
+        // still-showing error) is handled by handle_event; once there's
+        // nothing unsaved and nothing to dismiss, q/Esc exit the loop
+        // directly.
+        if state.mode == AppMode::Normal
+            && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+            && !state.dirty
+            && state.error_message.is_none()
+        {
+            break;
+        }
 
-                // Clear the command buffer when user presses Enter
-                KeyCode::Enter => {
-                    command_buffer.clear();
-                }
+        if handle_event(&mut state, AppEvent::KeyPress(key))? {
+            break;
+        }
 
-                // Ignore all other keys (like Ctrl, Alt, function keys, etc.)
-                _ => {}
-            }
+        // The `:w` command sets this instead of saving directly, since it's
+        // dispatched from deep inside `app::events` which has no access to
+        // `CATALOGUE_PATH` or `save_catalogue`.
+        if state.save_requested {
+            save(&mut state);
+            state.save_requested = false;
         }
     }
 
-    // Return Ok(()) to indicate the run() function succeeded
     Ok(())
 }
+
+/// Write the catalogue to `CATALOGUE_PATH`, clearing the dirty flag on
+/// success or surfacing the error otherwise. Shared by the Ctrl+S shortcut
+/// and the `:w` command.
+fn save(state: &mut AppState) {
+    match save_catalogue(CATALOGUE_PATH, &state.fruits) {
+        Ok(()) => {
+            state.dirty = false;
+            state.clear_error();
+        }
+        Err(e) => state.set_error(format!("Failed to save: {e}")),
+    }
+}
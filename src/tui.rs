@@ -0,0 +1,66 @@
+//! Terminal setup helpers that live outside the normal `AppError` path.
+//!
+//! `ratatui::init`/`ratatui::restore` already handle the happy path of
+//! entering/leaving raw mode and the alternate screen, but a panic skips
+//! straight past `ratatui::restore()`, leaving the terminal in raw mode
+//! with the alternate screen still active and the panic message scrambled.
+//! `init_panic_hook` patches that by restoring the terminal from within the
+//! panic hook itself before handing off to whatever hook was previously
+//! installed.
+
+use ratatui::crossterm::event::{
+    DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{disable_raw_mode, LeaveAlternateScreen};
+
+/// Install a panic hook that restores the terminal (raw mode + alternate
+/// screen) before printing the panic, so a crash never leaves the user's
+/// shell in a corrupted state. Call this once at startup, before
+/// `ratatui::init()`.
+pub fn init_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        restore_terminal();
+        previous_hook(panic_info);
+    }));
+}
+
+/// Turn on mouse reporting (clicks, drags, wheel) so the event loop starts
+/// receiving `Event::Mouse`. Call once after `ratatui::init()`; pair with
+/// `disable_mouse_capture` on the way out.
+pub fn enable_mouse_capture() -> std::io::Result<()> {
+    execute!(std::io::stdout(), EnableMouseCapture)
+}
+
+/// Turn mouse reporting back off so the terminal behaves normally again
+/// after the app exits.
+pub fn disable_mouse_capture() -> std::io::Result<()> {
+    execute!(std::io::stdout(), DisableMouseCapture)
+}
+
+/// Turn on bracketed paste so a multi-character clipboard paste arrives as
+/// a single `Event::Paste` instead of a flurry of `Event::Key`s. Call once
+/// after `ratatui::init()`; pair with `disable_bracketed_paste` on the way
+/// out.
+pub fn enable_bracketed_paste() -> std::io::Result<()> {
+    execute!(std::io::stdout(), EnableBracketedPaste)
+}
+
+/// Turn bracketed paste back off so the terminal behaves normally again
+/// after the app exits.
+pub fn disable_bracketed_paste() -> std::io::Result<()> {
+    execute!(std::io::stdout(), DisableBracketedPaste)
+}
+
+/// Best-effort terminal restore: a panic means we're already in a bad
+/// state, so errors here are swallowed rather than risking a double panic.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        std::io::stdout(),
+        DisableBracketedPaste,
+        DisableMouseCapture,
+        LeaveAlternateScreen
+    );
+}
@@ -0,0 +1,116 @@
+//! A "safe" area type that ties a `Rect` to the frame it came from.
+//!
+//! Before this, every popup recomputed its rect from a fresh
+//! `frame.area()` call, so a rect built against one frame size could end
+//! up rendered against a frame that had since resized. `Area` closes that
+//! hole: it can only be created from a live `Frame` (`Area::from_frame`),
+//! and every narrowing method (`centered`, `inner`, `split_vertical`)
+//! produces a new `Area` stamped with the same generation. Rendering
+//! through `Area::rect` checks, in debug builds, that the generation
+//! still matches the frame being drawn to.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    Frame,
+};
+
+/// A `Rect` paired with the generation of the `Frame` it was derived from.
+#[derive(Debug, Clone, Copy)]
+pub struct Area {
+    rect: Rect,
+    generation: usize,
+}
+
+impl Area {
+    /// The only entry point: capture the whole drawable area of `frame`,
+    /// stamped with its current generation.
+    pub fn from_frame(frame: &Frame) -> Self {
+        Self {
+            rect: frame.area(),
+            generation: frame.count(),
+        }
+    }
+
+    /// Resolve to a `Rect` for rendering. Panics in debug builds if `frame`
+    /// has moved on to a later generation than this `Area` was built from.
+    pub fn rect(&self, frame: &Frame) -> Rect {
+        self.assert_live(frame);
+        self.rect
+    }
+
+    /// Narrow to a sub-rect centered within this area, `percent_x` wide and
+    /// `percent_y` tall (of this area, not the whole frame).
+    pub fn centered(&self, percent_x: u16, percent_y: u16) -> Area {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage((100 - percent_y) / 2),
+                Constraint::Percentage(percent_y),
+                Constraint::Percentage((100 - percent_y) / 2),
+            ])
+            .split(self.rect);
+
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage((100 - percent_x) / 2),
+                Constraint::Percentage(percent_x),
+                Constraint::Percentage((100 - percent_x) / 2),
+            ])
+            .split(rows[1]);
+
+        self.derive(cols[1])
+    }
+
+    /// Shrink this area by `margin` cells on every side.
+    pub fn inner(&self, margin: u16) -> Area {
+        let rect = Layout::default()
+            .constraints([Constraint::Min(0)])
+            .margin(margin)
+            .split(self.rect)[0];
+        self.derive(rect)
+    }
+
+    /// Split into rows per `constraints`, each inheriting this generation.
+    pub fn split_vertical(&self, constraints: &[Constraint]) -> Vec<Area> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints.to_vec())
+            .split(self.rect)
+            .iter()
+            .map(|&rect| self.derive(rect))
+            .collect()
+    }
+
+    /// Split into columns per `constraints`, each inheriting this generation.
+    pub fn split_horizontal(&self, constraints: &[Constraint]) -> Vec<Area> {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints.to_vec())
+            .split(self.rect)
+            .iter()
+            .map(|&rect| self.derive(rect))
+            .collect()
+    }
+
+    fn derive(&self, rect: Rect) -> Area {
+        Area {
+            rect,
+            generation: self.generation,
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    fn assert_live(&self, frame: &Frame) {
+        assert_eq!(
+            self.generation,
+            frame.count(),
+            "stale Area (generation {}) used against frame generation {}",
+            self.generation,
+            frame.count(),
+        );
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn assert_live(&self, _frame: &Frame) {}
+}
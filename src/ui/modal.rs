@@ -1,5 +1,7 @@
 use crate::error::Result;
+use crate::ui::widget::{Interaction, InteractiveWidget, TextInputState};
 use fruitdata::FruitDimensions;
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
 /// Represents the current input field in a modal
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -32,14 +34,34 @@ impl InputField {
     }
 }
 
+/// Accepts any printable character; used for the `Name` field.
+fn filter_name(c: char, _existing: &str) -> Option<char> {
+    (!c.is_control()).then_some(c)
+}
+
+/// Accepts digits and a single `.`; used for the dimension fields.
+fn filter_dimension(c: char, existing: &str) -> Option<char> {
+    if c == '.' {
+        if existing.contains('.') {
+            None
+        } else {
+            Some(c)
+        }
+    } else if c.is_ascii_digit() {
+        Some(c)
+    } else {
+        None
+    }
+}
+
 /// Modal state for adding or editing fruits
 #[derive(Debug, Clone)]
 pub struct ModalState {
     /// The fruit being edited (or template for new fruit)
-    pub name: String,
-    pub length: String,
-    pub width: String,
-    pub height: String,
+    pub name: TextInputState,
+    pub length: TextInputState,
+    pub width: TextInputState,
+    pub height: TextInputState,
     /// Which field is currently focused
     pub focused_field: InputField,
     /// Error message within the modal
@@ -50,10 +72,10 @@ impl ModalState {
     /// Create a new modal for adding a fruit
     pub fn new() -> Self {
         Self {
-            name: String::new(),
-            length: String::new(),
-            width: String::new(),
-            height: String::new(),
+            name: TextInputState::new(filter_name),
+            length: TextInputState::new(filter_dimension),
+            width: TextInputState::new(filter_dimension),
+            height: TextInputState::new(filter_dimension),
             focused_field: InputField::Name,
             error: None,
         }
@@ -62,10 +84,10 @@ impl ModalState {
     /// Create a modal pre-filled with an existing fruit's data
     pub fn from_fruit(fruit: &FruitDimensions) -> Self {
         Self {
-            name: fruit.name.clone(),
-            length: fruit.length.to_string(),
-            width: fruit.width.to_string(),
-            height: fruit.height.to_string(),
+            name: TextInputState::with_value(fruit.name.clone(), filter_name),
+            length: TextInputState::with_value(fruit.length.to_string(), filter_dimension),
+            width: TextInputState::with_value(fruit.width.to_string(), filter_dimension),
+            height: TextInputState::with_value(fruit.height.to_string(), filter_dimension),
             focused_field: InputField::Name,
             error: None,
         }
@@ -81,72 +103,94 @@ impl ModalState {
         self.focused_field = self.focused_field.prev();
     }
 
-    /// Insert a character into the focused field
-    pub fn insert_char(&mut self, c: char) {
-        // Only allow valid characters for each field
-        match self.focused_field {
-            InputField::Name => {
-                self.name.push(c);
-                self.error = None;
-            }
-            InputField::Length | InputField::Width | InputField::Height => {
-                if c.is_ascii_digit() || c == '.' {
-                    match self.focused_field {
-                        InputField::Length => {
-                            self.length.push(c);
-                        }
-                        InputField::Width => {
-                            self.width.push(c);
-                        }
-                        InputField::Height => {
-                            self.height.push(c);
-                        }
-                        _ => {}
-                    }
-                    self.error = None;
-                }
-            }
+    fn field(&self, field: InputField) -> &TextInputState {
+        match field {
+            InputField::Name => &self.name,
+            InputField::Length => &self.length,
+            InputField::Width => &self.width,
+            InputField::Height => &self.height,
         }
     }
 
-    /// Remove the last character from the focused field
-    pub fn backspace(&mut self) {
-        match self.focused_field {
-            InputField::Name => {
-                self.name.pop();
-            }
-            InputField::Length => {
-                self.length.pop();
-            }
-            InputField::Width => {
-                self.width.pop();
-            }
-            InputField::Height => {
-                self.height.pop();
-            }
+    fn field_mut(&mut self, field: InputField) -> &mut TextInputState {
+        match field {
+            InputField::Name => &mut self.name,
+            InputField::Length => &mut self.length,
+            InputField::Width => &mut self.width,
+            InputField::Height => &mut self.height,
         }
     }
 
+    /// The currently focused field's state (read-only)
+    pub fn focused(&self) -> &TextInputState {
+        self.field(self.focused_field)
+    }
+
+    /// Insert a character at the cursor of the focused field, rejecting or
+    /// transforming it through that field's filter first.
+    pub fn insert_char(&mut self, c: char) {
+        self.field_mut(self.focused_field).insert_char(c);
+        self.error = None;
+    }
+
+    /// Insert `text` at the cursor of the focused field, running every
+    /// character through that field's filter (so e.g. pasting "12.5 cm"
+    /// into Length keeps only "12.5").
+    pub fn insert_str(&mut self, text: &str) {
+        self.field_mut(self.focused_field).insert_str(text);
+        self.error = None;
+    }
+
+    /// Remove the character before the cursor in the focused field
+    pub fn backspace(&mut self) {
+        self.field_mut(self.focused_field).backspace();
+    }
+
+    /// Remove the character under the cursor in the focused field
+    pub fn delete(&mut self) {
+        self.field_mut(self.focused_field).delete();
+    }
+
+    /// Move the cursor left in the focused field
+    pub fn move_left(&mut self) {
+        self.field_mut(self.focused_field).move_left();
+    }
+
+    /// Move the cursor right in the focused field
+    pub fn move_right(&mut self) {
+        self.field_mut(self.focused_field).move_right();
+    }
+
+    /// Move the cursor to the start of the focused field
+    pub fn move_home(&mut self) {
+        self.field_mut(self.focused_field).move_home();
+    }
+
+    /// Move the cursor to the end of the focused field
+    pub fn move_end(&mut self) {
+        self.field_mut(self.focused_field).move_end();
+    }
+
     /// Validate and convert to a FruitDimensions if valid
     pub fn validate_and_build(&mut self) -> Result<FruitDimensions> {
         // Validate name
-        if self.name.trim().is_empty() {
+        if self.name.value.trim().is_empty() {
             self.error = Some("Name cannot be empty".to_string());
             return Err(crate::error::AppError::Validation("Name cannot be empty".to_string()));
         }
 
         // Parse dimensions
-        let length: f32 = self.length.parse().map_err(|_| {
+        let length: f32 = self.length.value.parse().map_err(|_| {
             self.error = Some("Length must be a valid number".to_string());
             crate::error::AppError::Validation("Length must be a valid number".to_string())
         })?;
 
-        let width: f32 = self.width.parse().map_err(|_| {
+        let width: f32 = self.width.value.parse().map_err(|_| {
             self.error = Some("Width must be a valid number".to_string());
             crate::error::AppError::Validation("Width must be a valid number".to_string())
         })?;
 
-        let height: f32 = self.height.parse().map_err(|_| {
+        let height: f32 = self.height.value.parse().map_err(|_| {
             self.error = Some("Height must be a valid number".to_string());
             crate::error::AppError::Validation("Height must be a valid number".to_string())
         })?;
@@ -160,7 +204,7 @@ impl ModalState {
         }
 
         Ok(FruitDimensions {
-            name: self.name.trim().to_string(),
+            name: self.name.value.trim().to_string(),
             length,
             width,
             height,
@@ -178,3 +222,52 @@ impl Default for ModalState {
         Self::new()
     }
 }
+
+impl InteractiveWidget for ModalState {
+    /// Tab/Shift+Tab move between fields, arrows/Home/End/Backspace/Delete
+    /// edit the focused one, Enter reports a submission for the caller to
+    /// validate, and Esc cancels.
+    fn handle_key(&mut self, key: KeyEvent) -> Interaction {
+        match key.code {
+            KeyCode::Esc => Interaction::Cancelled,
+            KeyCode::Tab => {
+                self.next_field();
+                Interaction::Consumed
+            }
+            KeyCode::BackTab => {
+                self.prev_field();
+                Interaction::Consumed
+            }
+            KeyCode::Left => {
+                self.move_left();
+                Interaction::Consumed
+            }
+            KeyCode::Right => {
+                self.move_right();
+                Interaction::Consumed
+            }
+            KeyCode::Home => {
+                self.move_home();
+                Interaction::Consumed
+            }
+            KeyCode::End => {
+                self.move_end();
+                Interaction::Consumed
+            }
+            KeyCode::Backspace => {
+                self.backspace();
+                Interaction::Consumed
+            }
+            KeyCode::Delete => {
+                self.delete();
+                Interaction::Consumed
+            }
+            KeyCode::Char(c) => {
+                self.insert_char(c);
+                Interaction::Consumed
+            }
+            KeyCode::Enter => Interaction::Submitted,
+            _ => Interaction::Ignored,
+        }
+    }
+}
@@ -1,103 +1,186 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     text::{Line, Span},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Clear},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState,
+    },
     Frame,
 };
 use crate::app::state::{AppState, AppMode};
+use crate::config::{PanelConfig, UiConfig};
+use crate::ui::area::Area;
 use crate::ui::modal::InputField;
 
-pub fn render(frame: &mut Frame, state: &AppState) {
-    // Main layout
+pub fn render(frame: &mut Frame, state: &mut AppState, config: &UiConfig) {
+    // Main layout, split according to the configured panel constraints.
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .margin(1)
-        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .margin(config.layout.margin)
+        .constraints([
+            Constraint::Percentage(config.layout.list_constraint),
+            Constraint::Percentage(config.layout.details_constraint),
+        ])
         .split(frame.area());
 
     // Render list on the left
-    render_list(frame, state, chunks[0]);
+    render_list(frame, state, chunks[0], config);
 
     // Render details on the right
-    render_details(frame, state, chunks[1]);
+    render_details(frame, state, chunks[1], config);
 
-    // Render any active modal or error on top
+    // Render any active modal or error on top, anchored to this draw's
+    // generation so a stale popup rect can never slip through.
+    let root = Area::from_frame(frame);
     if let Some(err) = &state.error_message {
-        render_error_popup(frame, err);
+        render_error_popup(frame, root, err);
     } else if state.mode == AppMode::Help {
-        render_help_modal(frame);
+        render_help_modal(frame, root);
     } else if state.mode == AppMode::ConfirmDelete {
-        render_delete_confirm_modal(frame);
+        render_delete_confirm_modal(frame, root, state.delete_targets().len());
     } else if state.mode == AppMode::Filter {
-        render_filter_input(frame, state);
+        render_filter_input(frame, root, state);
+    } else if state.mode == AppMode::Command {
+        render_command_line(frame, root, state);
     } else if state.mode == AppMode::AddFruit || state.mode == AppMode::EditFruit {
-        if let Some(modal) = &state.modal {
+        if state.modal.is_some() {
             let title = if state.mode == AppMode::AddFruit {
                 "Add Fruit"
             } else {
                 "Edit Fruit"
             };
-            render_fruit_modal(frame, modal, title);
+            render_fruit_modal(frame, root, state, title);
         }
     }
 }
 
-fn render_list(frame: &mut Frame, state: &AppState, area: Rect) {
+fn render_list(frame: &mut Frame, state: &mut AppState, area: Rect, config: &UiConfig) {
+    let panel = &config.list;
     let display_fruits = state.display_fruits();
-    let items: Vec<ListItem> = display_fruits
+    let total = display_fruits.len();
+    let items: Vec<ListItem> = state
+        .filtered_indices
         .iter()
-        .map(|f| ListItem::new(f.name.as_str()))
+        .filter_map(|&i| state.fruits.get(i).map(|f| (i, f)))
+        .map(|(i, f)| {
+            let marked = state.marked.contains(&i);
+            let glyph = if marked { "✓ " } else { "  " };
+            let style = if marked {
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            ListItem::new(format!("{glyph}{}", f.name)).style(style)
+        })
         .collect();
 
-    let mut list_state = ratatui::widgets::ListState::default();
-    list_state.select(Some(state.selected_index));
-
+    let sort_arrow = if state.sort_ascending { "↑" } else { "↓" };
+    let marked_suffix = if state.marked.is_empty() {
+        String::new()
+    } else {
+        format!(" ({} sel)", state.marked.len())
+    };
     let title = if state.is_filtering() {
         format!(
-            "Fruits — (filtered: {}/{}) [/] search, [a] add, [e] edit, [d] delete, [Esc] clear",
+            "{} — (filtered: {}/{}) (sort: {} {}){} [/] search, [a] add, [e] edit, [d] delete, [Space] mark, [s] sort, [S] reverse, [Esc] clear",
+            panel.title,
             display_fruits.len(),
-            state.fruits.len()
+            state.fruits.len(),
+            state.sort_mode.label(),
+            sort_arrow,
+            marked_suffix,
         )
     } else {
-        "Fruits — [↑/↓/j/k] navigate, [a] add, [e] edit, [d] delete, [/] search, [?] help"
-            .to_string()
+        format!(
+            "{} — (sort: {} {}){} [↑/↓/j/k] navigate, [a] add, [e] edit, [d] delete, [Space] mark, [/] search, [s] sort, [S] reverse, [?] help",
+            panel.title,
+            state.sort_mode.label(),
+            sort_arrow,
+            marked_suffix,
+        )
     };
 
     let list = List::new(items)
-        .block(Block::default().title(title).borders(Borders::ALL))
+        .block(panel_block(panel, title, config.border_type()))
+        .style(Style::default().fg(panel.fg()).bg(panel.bg()))
+        .highlight_style(Style::default().fg(panel.highlight_fg()).bg(panel.highlight_bg()))
         .highlight_symbol(">> ");
 
-    frame.render_stateful_widget(list, area, &mut list_state);
+    frame.render_stateful_widget(list, area, &mut state.list_state);
+    // Store the area *inside* the list's own border, so a click row maps
+    // directly onto a visible item without the caller re-deriving the
+    // border offset.
+    state.hit_areas.list = Some(Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    });
+
+    if total > 0 {
+        let mut scrollbar_state = ScrollbarState::new(total).position(state.selected_index);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("↑"))
+            .end_symbol(Some("↓"));
+        frame.render_stateful_widget(
+            scrollbar,
+            area.inner(Margin { vertical: 1, horizontal: 0 }),
+            &mut scrollbar_state,
+        );
+    }
 }
 
-fn render_details(frame: &mut Frame, state: &AppState, area: Rect) {
+fn render_details(frame: &mut Frame, state: &AppState, area: Rect, config: &UiConfig) {
+    let panel = &config.details;
     let details = if state.fruits.is_empty() {
         Paragraph::new("No fruits available")
-            .block(Block::default().title("Details").borders(Borders::ALL))
+            .block(panel_block(panel, panel.title.clone(), config.border_type()))
     } else if let Some(fruit) = state.selected_fruit() {
         let txt = format!(
             "Name: {}\n\nDimensions:\n  Length: {}\n  Width : {}\n  Height: {}\n\nVolume: {:.2}",
             fruit.name, fruit.length, fruit.width, fruit.height, fruit.volume()
         );
-        Paragraph::new(txt).block(
-            Block::default()
-                .title(format!("Details [{}]", state.selected_index + 1))
-                .borders(Borders::ALL),
-        )
+        let title = format!("{} [{}]", panel.title, state.selected_index + 1);
+        Paragraph::new(txt).block(panel_block(panel, title, config.border_type()))
     } else {
         Paragraph::new("Select a fruit")
-            .block(Block::default().title("Details").borders(Borders::ALL))
+            .block(panel_block(panel, panel.title.clone(), config.border_type()))
     };
 
-    frame.render_widget(details, area);
+    frame.render_widget(details.style(Style::default().fg(panel.fg()).bg(panel.bg())), area);
 }
 
-fn render_filter_input(frame: &mut Frame, state: &AppState) {
-     let popup_area = centered_rect(60, 15, frame.area());
+/// Render `value` as a `Line` with the character at `cursor` highlighted to
+/// stand in for a real terminal cursor (ratatui has no inline cursor glyph).
+fn cursor_line(value: &str, cursor: usize, base_style: Style) -> Line<'static> {
+    let chars: Vec<char> = value.chars().collect();
+    let cursor = cursor.min(chars.len());
+    let before: String = chars[..cursor].iter().collect();
+    let after: String = chars[cursor.saturating_add(1).min(chars.len())..].iter().collect();
+    let at = chars.get(cursor).copied().unwrap_or(' ');
+
+    Line::from(vec![
+        Span::styled(before, base_style),
+        Span::styled(at.to_string(), Style::default().fg(Color::Black).bg(Color::Yellow)),
+        Span::styled(after, base_style),
+    ])
+}
+
+/// Build a bordered block for a panel using its configured title and colors.
+fn panel_block(panel: &PanelConfig, title: impl Into<String>, border_type: ratatui::widgets::BorderType) -> Block<'static> {
+    Block::default()
+        .title(title.into())
+        .borders(Borders::ALL)
+        .border_type(border_type)
+        .border_style(Style::default().fg(panel.fg()))
+}
+
+fn render_filter_input(frame: &mut Frame, area: Area, state: &AppState) {
+     let popup_area = area.centered(60, 15).rect(frame);
 
      frame.render_widget(Clear, popup_area);
-     
+
      // Create a search prompt with the current query
      let lines = vec![
          Line::from(vec![
@@ -110,7 +193,7 @@ fn render_filter_input(frame: &mut Frame, state: &AppState) {
          Line::from(""),
          Line::from(format!("> {}", state.filter_query)),
      ];
-     
+
      let para = Paragraph::new(lines)
          .block(Block::default().title("Filter Fruits").borders(Borders::ALL))
          .alignment(Alignment::Left);
@@ -118,13 +201,43 @@ fn render_filter_input(frame: &mut Frame, state: &AppState) {
      frame.render_widget(para, popup_area);
  }
 
-fn render_delete_confirm_modal(frame: &mut Frame) {
-    let popup_area = centered_rect(50, 15, frame.area());
+/// Render the `:`-command line as a single-line bar pinned to the bottom of
+/// the frame, vim-style, with the same highlighted-character cursor used by
+/// the modal fields.
+fn render_command_line(frame: &mut Frame, area: Area, state: &AppState) {
+    let frame_rect = area.rect(frame);
+    let bar = Rect {
+        x: frame_rect.x,
+        y: frame_rect.y + frame_rect.height.saturating_sub(1),
+        width: frame_rect.width,
+        height: 1,
+    };
+
+    frame.render_widget(Clear, bar);
+
+    // The leading ':' is drawn but not part of the buffer, so the cursor
+    // index is shifted by one to land on the right character.
+    let line = cursor_line(
+        &format!(":{}", state.command_line.value),
+        state.command_line.cursor + 1,
+        Style::default(),
+    );
+    frame.render_widget(Paragraph::new(line), bar);
+}
+
+fn render_delete_confirm_modal(frame: &mut Frame, area: Area, count: usize) {
+    let popup_area = area.centered(50, 15).rect(frame);
 
     frame.render_widget(Clear, popup_area);
 
+    let prompt = if count > 1 {
+        format!("Are you sure you want to delete {count} fruits?")
+    } else {
+        "Are you sure you want to delete this fruit?".to_string()
+    };
+
     let lines = vec![
-        Line::from("Are you sure you want to delete this fruit?"),
+        Line::from(prompt),
         Line::from(""),
         Line::from(vec![
             Span::raw("["),
@@ -142,109 +255,122 @@ fn render_delete_confirm_modal(frame: &mut Frame) {
     frame.render_widget(para, popup_area);
 }
 
-fn render_fruit_modal(frame: &mut Frame, modal: &crate::ui::modal::ModalState, title: &str) {
-     let frame_area = frame.area();
-     
+fn render_fruit_modal(frame: &mut Frame, area: Area, state: &mut AppState, title: &str) {
+     // Cloned so we can write hit-test rects back into `state` below without
+     // fighting the borrow checker over `state.modal` vs `state.hit_areas`.
+     let modal = state.modal.clone().expect("caller checked state.modal.is_some()");
+     let modal = &modal;
+     state.hit_areas.modal_fields.clear();
+     let frame_rect = area.rect(frame);
+
      // Make modal responsive to terminal size - use smaller percentages for small terminals
-     let width_percent = if frame_area.width < 80 { 90 } else if frame_area.width < 120 { 75 } else { 60 };
-     let height_percent = if frame_area.height < 20 { 80 } else if frame_area.height < 30 { 60 } else { 50 };
-     
-     let popup_area = centered_rect(width_percent, height_percent, frame_area);
-     frame.render_widget(Clear, popup_area);
+     let width_percent = if frame_rect.width < 80 { 90 } else if frame_rect.width < 120 { 75 } else { 60 };
+     let height_percent = if frame_rect.height < 20 { 80 } else if frame_rect.height < 30 { 60 } else { 50 };
+
+     let popup_area = area.centered(width_percent, height_percent);
+     frame.render_widget(Clear, popup_area.rect(frame));
 
      // Create the outer border
      let border = Block::default()
          .title(title)
          .borders(Borders::ALL)
          .border_type(ratatui::widgets::BorderType::Rounded);
-     frame.render_widget(border, popup_area);
-
-     // Create inner area for content (inside the border with 1px padding)
-     let inner_full = Layout::default()
-         .direction(Direction::Vertical)
-         .margin(1)
-         .constraints([Constraint::Min(0)])
-         .split(popup_area);
-     
-     let inner_area = inner_full[0];
-
-       let inner = Layout::default()
-           .direction(Direction::Vertical)
-           .constraints([
-               Constraint::Length(2),
-               Constraint::Length(2),
-               Constraint::Length(2),
-               Constraint::Length(2),
-               Constraint::Length(2),
-           ])
-           .split(inner_area);
-
-       // Helper function to render an input field with manual borders
-       let render_input_field = |frame: &mut Frame, area: Rect, label: &str, content: &str, focused: bool| {
+     frame.render_widget(border, popup_area.rect(frame));
+
+     // Inner area for content (inside the border with 1px padding)
+     let inner_area = popup_area.inner(1);
+
+       let inner = inner_area.split_vertical(&[
+           Constraint::Length(2),
+           Constraint::Length(2),
+           Constraint::Length(2),
+           Constraint::Length(2),
+           Constraint::Length(2),
+       ]);
+
+       // Helper function to render an input field with manual borders and,
+       // when focused, a highlighted character marking the cursor position.
+       let render_input_field = |frame: &mut Frame, area: Area, label: &str, field: &crate::ui::widget::TextInputState, focused: bool| {
            let style = if focused {
                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
            } else {
                Style::default()
            };
-           
+
+           let field_rect = area.rect(frame);
+
            // Render border
            let border = Block::default()
                .title(label)
                .borders(Borders::ALL)
                .style(style);
-           frame.render_widget(border, area);
-           
+           frame.render_widget(border, field_rect);
+
            // Render content inside (leaving space for borders: 1px on each side)
-           let content_area = Rect {
-               x: area.x + 1,
-               y: area.y + 1,
-               width: area.width.saturating_sub(2),
-               height: area.height.saturating_sub(2),
-           };
-           
+           let content_area = area.inner(1).rect(frame);
+
            if content_area.width > 0 && content_area.height > 0 {
-               let text_widget = Paragraph::new(content)
-                   .style(style);
+               let text_widget = if focused {
+                   Paragraph::new(cursor_line(&field.value, field.cursor, style))
+               } else {
+                   Paragraph::new(field.value.as_str()).style(style)
+               };
                frame.render_widget(text_widget, content_area);
            }
        };
 
        // Name field
        let focused_name = modal.focused_field == InputField::Name;
-       render_input_field(frame, inner[0], "Name", modal.name.as_str(), focused_name);
+       render_input_field(frame, inner[0], "Name", &modal.name, focused_name);
+       state.hit_areas.modal_fields.push((InputField::Name, inner[0].rect(frame)));
 
        // Length field
        let focused_length = modal.focused_field == InputField::Length;
-       render_input_field(frame, inner[1], "Length", modal.length.as_str(), focused_length);
+       render_input_field(frame, inner[1], "Length", &modal.length, focused_length);
+       state.hit_areas.modal_fields.push((InputField::Length, inner[1].rect(frame)));
 
        // Width field
        let focused_width = modal.focused_field == InputField::Width;
-       render_input_field(frame, inner[2], "Width", modal.width.as_str(), focused_width);
+       render_input_field(frame, inner[2], "Width", &modal.width, focused_width);
+       state.hit_areas.modal_fields.push((InputField::Width, inner[2].rect(frame)));
 
        // Height field
        let focused_height = modal.focused_field == InputField::Height;
-       render_input_field(frame, inner[3], "Height", modal.height.as_str(), focused_height);
+       render_input_field(frame, inner[3], "Height", &modal.height, focused_height);
+       state.hit_areas.modal_fields.push((InputField::Height, inner[3].rect(frame)));
+
+     // Instructions, split into a clickable OK half and Cancel half.
+     let buttons = inner[4].split_horizontal(&[Constraint::Percentage(50), Constraint::Percentage(50)]);
 
-     // Instructions
-     let instructions = Line::from(vec![
+     let ok_instructions = Line::from(vec![
          Span::raw("["),
          Span::styled("Tab", Style::default().fg(Color::Cyan)),
          Span::raw("] next  ["),
+         Span::styled("Enter", Style::default().fg(Color::Green)),
+         Span::raw("] save"),
+     ]);
+     let ok_widget = Paragraph::new(ok_instructions)
+         .block(Block::default().borders(Borders::ALL))
+         .alignment(Alignment::Center);
+     frame.render_widget(ok_widget, buttons[0].rect(frame));
+     state.hit_areas.modal_ok = Some(buttons[0].rect(frame));
+
+     let cancel_instructions = Line::from(vec![
+         Span::raw("["),
          Span::styled("S-Tab", Style::default().fg(Color::Cyan)),
          Span::raw("] prev  ["),
-         Span::styled("Enter", Style::default().fg(Color::Green)),
-         Span::raw("] save  ["),
          Span::styled("Esc", Style::default().fg(Color::Red)),
          Span::raw("] cancel"),
      ]);
-     let instructions_widget = Paragraph::new(instructions)
+     let cancel_widget = Paragraph::new(cancel_instructions)
          .block(Block::default().borders(Borders::ALL))
          .alignment(Alignment::Center);
-     frame.render_widget(instructions_widget, inner[4]);
+     frame.render_widget(cancel_widget, buttons[1].rect(frame));
+     state.hit_areas.modal_cancel = Some(buttons[1].rect(frame));
 
      // Error message if present
      if let Some(err) = &modal.error {
-         let error_area = centered_rect(50, 15, frame.area());
+         let error_area = area.centered(50, 15).rect(frame);
          frame.render_widget(
              Paragraph::new(err.as_str())
                  .block(Block::default().title("Error").borders(Borders::ALL))
@@ -255,52 +381,20 @@ fn render_fruit_modal(frame: &mut Frame, modal: &crate::ui::modal::ModalState, t
      }
  }
 
-fn render_error_popup(frame: &mut Frame, message: &str) {
-    let popup_area = centered_rect(70, 20, frame.area());
+fn render_error_popup(frame: &mut Frame, area: Area, message: &str) {
+    let popup_area = area.centered(70, 20).rect(frame);
 
     frame.render_widget(Clear, popup_area);
 
     let para = Paragraph::new(message)
-        .block(Block::default().title("Error").borders(Borders::ALL))
+        .block(Block::default().title("Error (Enter to dismiss)").borders(Borders::ALL))
         .alignment(Alignment::Center);
 
     frame.render_widget(para, popup_area);
 }
 
-/// Helper function to create a centered rect
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
-
-fn render_help_modal(frame: &mut Frame) {
-    let area = frame.area();
-    let width = (area.width * 70) / 100;
-    let height = (area.height * 80) / 100;
-    let x = (area.width - width) / 2;
-    let y = (area.height - height) / 2;
-    
-    let popup_area = Rect {
-        x,
-        y,
-        width,
-        height,
-    };
+fn render_help_modal(frame: &mut Frame, area: Area) {
+    let popup_area = area.centered(70, 80).rect(frame);
 
     // Create the help text
     let help_text = vec![
@@ -316,7 +410,10 @@ fn render_help_modal(frame: &mut Frame) {
         Line::from("  /            - Filter by name"),
         Line::from("  a            - Add new fruit"),
         Line::from("  e            - Edit selected fruit"),
-        Line::from("  d            - Delete selected fruit"),
+        Line::from("  d            - Delete selected fruit (or all marked, if any)"),
+        Line::from("  Space        - Toggle mark on selected fruit"),
+        Line::from("  s            - Cycle sort key (name/volume/length/width/height)"),
+        Line::from("  S            - Toggle sort direction (ascending/descending)"),
         Line::from("  Ctrl+S       - Save changes"),
         Line::from(""),
         Line::from(vec![Span::styled("Modal Navigation", Style::default().fg(Color::Cyan))]),
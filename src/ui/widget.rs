@@ -0,0 +1,165 @@
+//! A small trait for widgets that own their own key handling.
+//!
+//! Before this, every modal grew its own `match key.code { ... }` block in
+//! `app::events`, repeating the same Tab/arrow/Backspace/Delete wiring per
+//! prompt. `InteractiveWidget` pulls that wiring into the widget itself:
+//! callers feed it a `KeyEvent` and react to the `Interaction` it reports,
+//! instead of re-deriving what each key means for every new prompt.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+/// What happened when a key was routed into an `InteractiveWidget`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interaction {
+    /// The key was handled; nothing else for the caller to do.
+    Consumed,
+    /// The widget had no use for this key; the caller decides what it means.
+    Ignored,
+    /// The widget's content was submitted (e.g. Enter).
+    Submitted,
+    /// The widget was dismissed without submitting (e.g. Esc).
+    Cancelled,
+}
+
+/// A widget that handles its own keystrokes, reporting back a high-level
+/// `Interaction` rather than leaving the caller to match on `KeyCode`.
+pub trait InteractiveWidget {
+    /// Handle a single key event, returning what happened.
+    fn handle_key(&mut self, key: KeyEvent) -> Interaction;
+}
+
+/// A single-line text input: value, cursor, and a character filter run on
+/// every keystroke to restrict what can be typed (see `filter_char`).
+#[derive(Debug, Clone)]
+pub struct TextInputState {
+    pub value: String,
+    /// Cursor position as a char index, in `0..=value.chars().count()`.
+    pub cursor: usize,
+    /// Rejects or transforms a character about to be typed/pasted, given
+    /// the field's current value (e.g. to allow only one `.` in a number).
+    filter_char: fn(char, &str) -> Option<char>,
+}
+
+impl TextInputState {
+    /// An empty input using `filter_char` to restrict what can be typed.
+    pub fn new(filter_char: fn(char, &str) -> Option<char>) -> Self {
+        Self { value: String::new(), cursor: 0, filter_char }
+    }
+
+    /// An input pre-filled with `value`, cursor at the end.
+    pub fn with_value(value: impl Into<String>, filter_char: fn(char, &str) -> Option<char>) -> Self {
+        let value = value.into();
+        let cursor = value.chars().count();
+        Self { value, cursor, filter_char }
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.value.len())
+    }
+
+    /// Run `c` through this field's filter and, if accepted, insert it at
+    /// the cursor and advance past it.
+    pub fn insert_char(&mut self, c: char) {
+        if let Some(c) = (self.filter_char)(c, &self.value) {
+            let idx = self.byte_index(self.cursor);
+            self.value.insert(idx, c);
+            self.cursor += 1;
+        }
+    }
+
+    /// Insert every character of `text` at the cursor, each filtered the
+    /// same as a single typed character (used for pasting).
+    pub fn insert_str(&mut self, text: &str) {
+        for c in text.chars() {
+            self.insert_char(c);
+        }
+    }
+
+    /// Remove the character before the cursor (Backspace).
+    pub fn backspace(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        let start = self.byte_index(self.cursor - 1);
+        let end = self.byte_index(self.cursor);
+        self.value.replace_range(start..end, "");
+        self.cursor -= 1;
+    }
+
+    /// Remove the character under the cursor (Delete).
+    pub fn delete(&mut self) {
+        if self.cursor >= self.value.chars().count() {
+            return;
+        }
+        let start = self.byte_index(self.cursor);
+        let end = self.byte_index(self.cursor + 1);
+        self.value.replace_range(start..end, "");
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.value.chars().count());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.value.chars().count();
+    }
+
+    /// Empty the value and reset the cursor, keeping the filter.
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+}
+
+impl InteractiveWidget for TextInputState {
+    /// Standard line-editor bindings: arrows/Home/End move, Backspace/Delete
+    /// edit, Enter submits, Esc cancels, everything else either inserts (if
+    /// a plain char) or is ignored for the caller to interpret.
+    fn handle_key(&mut self, key: KeyEvent) -> Interaction {
+        match key.code {
+            KeyCode::Esc => Interaction::Cancelled,
+            KeyCode::Enter => Interaction::Submitted,
+            KeyCode::Left => {
+                self.move_left();
+                Interaction::Consumed
+            }
+            KeyCode::Right => {
+                self.move_right();
+                Interaction::Consumed
+            }
+            KeyCode::Home => {
+                self.move_home();
+                Interaction::Consumed
+            }
+            KeyCode::End => {
+                self.move_end();
+                Interaction::Consumed
+            }
+            KeyCode::Backspace => {
+                self.backspace();
+                Interaction::Consumed
+            }
+            KeyCode::Delete => {
+                self.delete();
+                Interaction::Consumed
+            }
+            KeyCode::Char(c) => {
+                self.insert_char(c);
+                Interaction::Consumed
+            }
+            _ => Interaction::Ignored,
+        }
+    }
+}